@@ -14,12 +14,41 @@ pub trait IndicatorConfig {
 	/// Name of an indicator
 	const NAME: &'static str;
 
-	/// Validates if **Configuration** is OK
-	fn validate(&self) -> bool;
+	/// Validates if **Configuration** is OK, returning an [`Error`](crate::core::Error) naming
+	/// the offending parameter and its constraint when it is not
+	fn validate(&self) -> Result<(), Error>;
+
+	/// Returns `true` if the **Configuration** is valid, `false` otherwise
+	///
+	/// This is a convenience wrapper around [`validate`](Self::validate) for call sites that
+	/// only need a boolean answer
+	fn is_valid(&self) -> bool {
+		self.validate().is_ok()
+	}
 
 	/// Dynamically sets **Configuration** parameters
 	fn set(&mut self, name: &str, value: String) -> Result<(), Error>;
 
+	/// Dynamically sets several **Configuration** parameters at once
+	///
+	/// Applies each `(name, value)` pair in order via [`set`](Self::set) and stops at the first
+	/// one that fails, returning its error
+	fn set_many(&mut self, params: &[(&str, String)]) -> Result<(), Error> {
+		for (name, value) in params {
+			self.set(name, value.clone())?;
+		}
+
+		Ok(())
+	}
+
+	/// Returns the current values of all tunable **Configuration** parameters as `(name, value)`
+	/// pairs
+	///
+	/// Together with [`set_many`](Self::set_many) this allows a config to be round-tripped
+	/// through a `name -> value` map (enumerate, persist, `set_many` to restore) without the
+	/// caller knowing its concrete type
+	fn parameters(&self) -> Vec<(&'static str, String)>;
+
 	/// Returns a name of the indicator
 	fn name(&self) -> &'static str {
 		Self::NAME
@@ -59,4 +88,90 @@ pub trait IndicatorConfig {
 
 		Ok(result)
 	}
+
+	/// Evaluates indicator config over an owned sequence of OHLC lazily, yielding one
+	/// [`IndicatorResult`](crate::core::IndicatorResult) per input value instead of allocating
+	/// the whole result `Vec` up front
+	///
+	/// The **State** is initialized from the first item of `iter`. If `iter` is empty, the
+	/// returned iterator is empty as well. When `iter` is not empty, initialization happens
+	/// eagerly, so an `init` failure is reported through the outer `Result` before iteration
+	/// begins; an empty `iter` yields `Ok` without calling `init` at all
+	/// ```
+	/// use yata::prelude::*;
+	/// use yata::helpers::{RandomCandles};
+	/// use yata::indicators::Trix;
+	///
+	/// let candles: Vec<_> = RandomCandles::new().take(10).collect();
+	/// let trix = Trix::default();
+	/// let results: Vec<_> = trix.eval_iter(candles).unwrap().collect();
+	/// println!("{:?}", results);
+	/// ```
+	fn eval_iter<T: OHLCV, I: IntoIterator<Item = T>>(
+		self,
+		iter: I,
+	) -> Result<impl Iterator<Item = IndicatorResult>, Error>
+	where
+		Self: Sized,
+	{
+		let mut iter = iter.into_iter();
+		let first_element = iter.next();
+
+		let state = match &first_element {
+			Some(x) => Some(self.init(x)?),
+			None => None,
+		};
+
+		Ok(state
+			.map(move |mut state| {
+				std::iter::once(first_element.unwrap())
+					.chain(iter)
+					.map(move |x| state.next(&x))
+			})
+			.into_iter()
+			.flatten())
+	}
+
+	/// Evaluates indicator config over a borrowed sequence of OHLC lazily, yielding one
+	/// [`IndicatorResult`](crate::core::IndicatorResult) per input value instead of allocating
+	/// the whole result `Vec` up front
+	///
+	/// This is the borrowing counterpart of [`eval_iter`](Self::eval_iter): the **State** is
+	/// initialized from the first item of `iter`, and the returned iterator is empty when `iter`
+	/// is empty
+	/// ```
+	/// use yata::prelude::*;
+	/// use yata::helpers::{RandomCandles};
+	/// use yata::indicators::Trix;
+	///
+	/// let candles: Vec<_> = RandomCandles::new().take(10).collect();
+	/// let trix = Trix::default();
+	/// let results: Vec<_> = trix.over_iter(&candles).unwrap().collect();
+	/// println!("{:?}", results);
+	/// ```
+	fn over_iter<'a, T: OHLCV + 'a, I: IntoIterator<Item = &'a T>>(
+		self,
+		iter: I,
+	) -> Result<impl Iterator<Item = IndicatorResult> + 'a, Error>
+	where
+		Self: Sized + 'a,
+		I::IntoIter: 'a,
+	{
+		let mut iter = iter.into_iter();
+		let first_element = iter.next();
+
+		let state = match first_element {
+			Some(x) => Some(self.init(x)?),
+			None => None,
+		};
+
+		Ok(state
+			.map(move |mut state| {
+				std::iter::once(first_element.unwrap())
+					.chain(iter)
+					.map(move |x| state.next(x))
+			})
+			.into_iter()
+			.flatten())
+	}
 }